@@ -0,0 +1,6 @@
+pub mod lp_format;
+pub mod problem;
+#[cfg(feature = "simplex")]
+pub mod simplex;
+pub mod solvers;
+pub mod util;
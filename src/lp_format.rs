@@ -0,0 +1,207 @@
+//! The CPLEX LP and MPS file formats: the representations every solver
+//! backend in this crate reads from (via a temp file) and that
+//! [`crate::problem::Problem`] implements.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+
+/// Direction of optimization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LpObjective {
+    Minimize,
+    Maximize,
+}
+
+/// The relational operator of a constraint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    LessOrEqual,
+    GreaterOrEqual,
+    Equal,
+}
+
+/// A linear combination of variables: `coefficient * variable` terms, summed.
+pub type LpExpression = Vec<(String, f64)>;
+
+/// A single named linear constraint: `expression <op> rhs`.
+#[derive(Debug, Clone)]
+pub struct LpConstraint {
+    pub name: String,
+    pub expression: LpExpression,
+    pub operator: Operator,
+    pub rhs: f64,
+}
+
+/// The bounds (and, implicitly, the kind) of a variable.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VarBounds {
+    Continuous { lower: f64, upper: f64 },
+    Integer { lower: i64, upper: i64 },
+    Binary,
+}
+
+/// A variable declaration: its name and its bounds.
+#[derive(Debug, Clone)]
+pub struct LpVariable {
+    pub name: String,
+    pub bounds: VarBounds,
+}
+
+/// A solver-agnostic linear program: an objective, its variables and its
+/// constraints.
+pub trait LpProblem<'a> {
+    fn name(&self) -> &str;
+    fn objective_direction(&self) -> LpObjective;
+    fn objective(&'a self) -> &'a LpExpression;
+    fn variables(&'a self) -> std::slice::Iter<'a, LpVariable>;
+    fn constraints(&'a self) -> std::slice::Iter<'a, LpConstraint>;
+}
+
+/// Anything that can render itself in CPLEX LP format.
+pub trait LpFileFormat {
+    fn to_lp_file_format(&self) -> String;
+
+    /// Write this value to `path` in CPLEX LP format.
+    fn write_lp(&self, path: &str) -> io::Result<()> {
+        fs::write(path, self.to_lp_file_format())
+    }
+}
+
+/// The input file format a [`crate::solvers::SolverProgram`] expects its
+/// problem in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputFormat {
+    /// CPLEX LP format, written by [`LpFileFormat`].
+    Lp,
+    /// MPS format, written by [`MpsFileFormat`].
+    Mps,
+}
+
+/// A whole linear program that can render itself in (free) MPS format.
+///
+/// Unlike [`LpFileFormat`], this is only implemented for full problems: MPS
+/// has no notion of an arbitrary sub-expression.
+pub trait MpsFileFormat<'a>: LpProblem<'a> {
+    /// Render this problem as free MPS: fields are whitespace-separated
+    /// rather than aligned to fixed columns, which every MPS reader this
+    /// crate talks to accepts.
+    fn to_mps_file_format(&'a self) -> String {
+        let mut out = format!("NAME          {}\n", self.name());
+
+        out.push_str("ROWS\n");
+        out.push_str(" N  obj\n");
+        for constraint in self.constraints() {
+            let kind = match constraint.operator {
+                Operator::LessOrEqual => 'L',
+                Operator::GreaterOrEqual => 'G',
+                Operator::Equal => 'E',
+            };
+            out.push_str(&format!(" {}  {}\n", kind, constraint.name));
+        }
+
+        // Coefficients grouped by variable, in declaration order, since MPS
+        // COLUMNS entries for a variable must be contiguous.
+        let mut by_variable: BTreeMap<&str, Vec<(&str, f64)>> = BTreeMap::new();
+        for variable in self.variables() {
+            by_variable.entry(&variable.name).or_default();
+        }
+        for (name, coef) in self.objective() {
+            if let Some(rows) = by_variable.get_mut(name.as_str()) {
+                rows.push(("obj", *coef));
+            }
+        }
+        for constraint in self.constraints() {
+            for (name, coef) in &constraint.expression {
+                if let Some(rows) = by_variable.get_mut(name.as_str()) {
+                    rows.push((&constraint.name, *coef));
+                }
+            }
+        }
+
+        out.push_str("COLUMNS\n");
+        for variable in self.variables() {
+            let rows = &by_variable[variable.name.as_str()];
+            if rows.is_empty() {
+                // A variable with no nonzero coefficient anywhere (e.g. one
+                // only ever mentioned in `Bounds`) still needs a COLUMNS
+                // entry: most MPS readers reject a bound on an undeclared
+                // column.
+                out.push_str(&format!("    {}  obj  0\n", variable.name));
+                continue;
+            }
+            for (row, coef) in rows {
+                out.push_str(&format!("    {}  {}  {}\n", variable.name, row, coef));
+            }
+        }
+
+        out.push_str("RHS\n");
+        for constraint in self.constraints() {
+            out.push_str(&format!(
+                "    RHS  {}  {}\n",
+                constraint.name, constraint.rhs
+            ));
+        }
+
+        out.push_str("BOUNDS\n");
+        for variable in self.variables() {
+            match variable.bounds {
+                VarBounds::Binary => out.push_str(&format!(" BV BND  {}\n", variable.name)),
+                VarBounds::Integer { lower, upper } => {
+                    out.push_str(&format!(" LI BND  {}  {}\n", variable.name, lower));
+                    out.push_str(&format!(" UI BND  {}  {}\n", variable.name, upper));
+                }
+                VarBounds::Continuous { lower, upper } if lower.is_infinite() && upper.is_infinite() => {
+                    out.push_str(&format!(" FR BND  {}\n", variable.name));
+                }
+                VarBounds::Continuous { lower, upper } => {
+                    // MPS's implicit default bound is [0, +inf); only emit
+                    // the bound lines that actually narrow it. A negative
+                    // lower bound needs `MI` (lower = -inf) rather than `LO`,
+                    // since `LO`'s value must be a finite number.
+                    if lower == f64::NEG_INFINITY {
+                        out.push_str(&format!(" MI BND  {}\n", variable.name));
+                    } else if lower != 0.0 {
+                        out.push_str(&format!(" LO BND  {}  {}\n", variable.name, lower));
+                    }
+                    if upper.is_finite() {
+                        out.push_str(&format!(" UP BND  {}  {}\n", variable.name, upper));
+                    }
+                }
+            }
+        }
+
+        out.push_str("ENDATA\n");
+        out
+    }
+
+    /// Write this problem to `path` in free MPS format.
+    fn write_mps(&'a self, path: &str) -> io::Result<()> {
+        fs::write(path, self.to_mps_file_format())
+    }
+}
+
+pub(crate) fn operator_symbol(op: Operator) -> &'static str {
+    match op {
+        Operator::LessOrEqual => "<=",
+        Operator::GreaterOrEqual => ">=",
+        Operator::Equal => "=",
+    }
+}
+
+pub(crate) fn format_expression(expr: &LpExpression) -> String {
+    let mut out = String::new();
+    for (i, (name, coef)) in expr.iter().enumerate() {
+        if i > 0 {
+            out.push_str(if *coef < 0.0 { " - " } else { " + " });
+        } else if *coef < 0.0 {
+            out.push('-');
+        }
+        let coef = coef.abs();
+        if (coef - 1.0).abs() > f64::EPSILON {
+            out.push_str(&format!("{} ", coef));
+        }
+        out.push_str(name);
+    }
+    out
+}
@@ -0,0 +1,536 @@
+//! The concrete [`Problem`] type built by the modeling DSL and fed to solvers.
+
+use std::collections::HashMap;
+use std::fs;
+
+use crate::lp_format::{
+    format_expression, operator_symbol, LpConstraint, LpExpression, LpFileFormat, LpObjective,
+    LpProblem, LpVariable, MpsFileFormat, Operator, VarBounds,
+};
+
+/// A linear (or mixed-integer) program: an objective, its variables and its
+/// constraints.
+#[derive(Debug, Clone)]
+pub struct Problem {
+    pub name: String,
+    pub objective_direction: LpObjective,
+    pub objective: LpExpression,
+    pub variables: Vec<LpVariable>,
+    pub constraints: Vec<LpConstraint>,
+}
+
+impl Problem {
+    pub fn new(name: &str, objective_direction: LpObjective) -> Self {
+        Self {
+            name: name.to_string(),
+            objective_direction,
+            objective: Vec::new(),
+            variables: Vec::new(),
+            constraints: Vec::new(),
+        }
+    }
+}
+
+impl<'a> LpProblem<'a> for Problem {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn objective_direction(&self) -> LpObjective {
+        self.objective_direction
+    }
+
+    fn objective(&'a self) -> &'a LpExpression {
+        &self.objective
+    }
+
+    fn variables(&'a self) -> std::slice::Iter<'a, LpVariable> {
+        self.variables.iter()
+    }
+
+    fn constraints(&'a self) -> std::slice::Iter<'a, LpConstraint> {
+        self.constraints.iter()
+    }
+}
+
+impl LpFileFormat for Problem {
+    fn to_lp_file_format(&self) -> String {
+        let direction = match self.objective_direction {
+            LpObjective::Maximize => "Maximize",
+            LpObjective::Minimize => "Minimize",
+        };
+
+        let mut out = format!(
+            "\\ {}\n\n{}\nobj: {}\n\nSubject To\n",
+            self.name,
+            direction,
+            format_expression(&self.objective)
+        );
+
+        for constraint in &self.constraints {
+            out.push_str(&format!(
+                "{}: {} {} {}\n",
+                constraint.name,
+                format_expression(&constraint.expression),
+                operator_symbol(constraint.operator),
+                constraint.rhs
+            ));
+        }
+
+        out.push_str("\nBounds\n");
+        let mut binaries = Vec::new();
+        let mut generals = Vec::new();
+        for variable in &self.variables {
+            match variable.bounds {
+                VarBounds::Continuous { lower, upper } => {
+                    out.push_str(&format!("{} <= {} <= {}\n", lower, variable.name, upper));
+                }
+                VarBounds::Integer { lower, upper } => {
+                    out.push_str(&format!("{} <= {} <= {}\n", lower, variable.name, upper));
+                    generals.push(variable.name.clone());
+                }
+                VarBounds::Binary => binaries.push(variable.name.clone()),
+            }
+        }
+
+        if !generals.is_empty() {
+            out.push_str("\nGenerals\n");
+            out.push_str(&generals.join(" "));
+            out.push('\n');
+        }
+
+        if !binaries.is_empty() {
+            out.push_str("\nBinaries\n");
+            out.push_str(&binaries.join(" "));
+            out.push('\n');
+        }
+
+        out.push_str("\nEnd\n");
+        out
+    }
+}
+
+impl<'a> MpsFileFormat<'a> for Problem {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    None,
+    Objective,
+    Constraints,
+    Bounds,
+    Generals,
+    Binaries,
+}
+
+/// Tracks variables in first-seen order while parsing, so a variable
+/// mentioned only in the objective or a constraint (and never in `Bounds`)
+/// still ends up with a default continuous `[0, +inf)` range.
+struct VariableTable {
+    order: Vec<LpVariable>,
+    index: HashMap<String, usize>,
+}
+
+impl VariableTable {
+    fn new() -> Self {
+        Self {
+            order: Vec::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    fn entry(&mut self, name: &str) -> &mut LpVariable {
+        if !self.index.contains_key(name) {
+            let idx = self.order.len();
+            self.order.push(LpVariable {
+                name: name.to_string(),
+                bounds: VarBounds::Continuous {
+                    lower: 0.0,
+                    upper: f64::INFINITY,
+                },
+            });
+            self.index.insert(name.to_string(), idx);
+        }
+        let idx = self.index[name];
+        &mut self.order[idx]
+    }
+
+    fn register_all(&mut self, expr: &LpExpression) {
+        for (name, _) in expr {
+            self.entry(name);
+        }
+    }
+}
+
+impl Problem {
+    /// Parse a problem out of CPLEX LP source, in the grammar [`Self::to_lp_file_format`]
+    /// emits: an objective section, a `Subject To` block of named constraints,
+    /// and optional `Bounds`, `Generals` and `Binaries` sections.
+    pub fn parse_lp(input: &str) -> Result<Problem, String> {
+        let mut name = String::new();
+        let mut objective_direction = LpObjective::Minimize;
+        let mut objective = LpExpression::new();
+        let mut constraints = Vec::new();
+        let mut variables = VariableTable::new();
+        let mut section = Section::None;
+
+        for raw_line in input.lines() {
+            let line = raw_line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(comment) = line.strip_prefix('\\') {
+                if name.is_empty() {
+                    name = comment.trim().to_string();
+                }
+                continue;
+            }
+
+            let lower = line.to_lowercase();
+            match lower.as_str() {
+                "maximize" | "maximise" | "max" => {
+                    objective_direction = LpObjective::Maximize;
+                    section = Section::Objective;
+                    continue;
+                }
+                "minimize" | "minimise" | "min" => {
+                    objective_direction = LpObjective::Minimize;
+                    section = Section::Objective;
+                    continue;
+                }
+                "subject to" | "such that" | "st" => {
+                    section = Section::Constraints;
+                    continue;
+                }
+                "bounds" => {
+                    section = Section::Bounds;
+                    continue;
+                }
+                "generals" | "general" | "integers" => {
+                    section = Section::Generals;
+                    continue;
+                }
+                "binaries" | "binary" => {
+                    section = Section::Binaries;
+                    continue;
+                }
+                "end" => break,
+                _ => {}
+            }
+
+            match section {
+                Section::Objective => {
+                    let expr_part = line.splitn(2, ':').last().unwrap_or(line);
+                    objective = parse_expression(expr_part)?;
+                    variables.register_all(&objective);
+                }
+                Section::Constraints => {
+                    let mut parts = line.splitn(2, ':');
+                    let cname = parts.next().unwrap_or_default().trim().to_string();
+                    let rest = parts
+                        .next()
+                        .ok_or_else(|| format!("constraint missing a name: {:?}", line))?;
+                    let (operator, symbol) = if rest.contains("<=") {
+                        (Operator::LessOrEqual, "<=")
+                    } else if rest.contains(">=") {
+                        (Operator::GreaterOrEqual, ">=")
+                    } else if rest.contains('=') {
+                        (Operator::Equal, "=")
+                    } else {
+                        return Err(format!("constraint missing an operator: {:?}", line));
+                    };
+                    let mut sides = rest.splitn(2, symbol);
+                    let expr_part = sides.next().unwrap_or_default();
+                    let rhs_part = sides
+                        .next()
+                        .ok_or_else(|| format!("constraint missing a right-hand side: {:?}", line))?;
+                    let expression = parse_expression(expr_part)?;
+                    variables.register_all(&expression);
+                    let rhs: f64 = rhs_part
+                        .trim()
+                        .parse()
+                        .map_err(|e| format!("invalid constraint rhs {:?}: {}", rhs_part, e))?;
+                    constraints.push(LpConstraint {
+                        name: cname,
+                        expression,
+                        operator,
+                        rhs,
+                    });
+                }
+                Section::Bounds => parse_bound_line(line, &mut variables)?,
+                Section::Generals => {
+                    for token in line.split_whitespace() {
+                        let variable = variables.entry(token);
+                        let (lower, upper) = match variable.bounds {
+                            VarBounds::Continuous { lower, upper } => (lower, upper),
+                            _ => (0.0, f64::INFINITY),
+                        };
+                        variable.bounds = VarBounds::Integer {
+                            lower: lower as i64,
+                            upper: if upper.is_finite() {
+                                upper as i64
+                            } else {
+                                i64::MAX
+                            },
+                        };
+                    }
+                }
+                Section::Binaries => {
+                    for token in line.split_whitespace() {
+                        variables.entry(token).bounds = VarBounds::Binary;
+                    }
+                }
+                Section::None => {}
+            }
+        }
+
+        Ok(Problem {
+            name,
+            objective_direction,
+            objective,
+            variables: variables.order,
+            constraints,
+        })
+    }
+
+    /// Read and parse an LP file from `path`.
+    pub fn read_lp(path: &str) -> Result<Problem, String> {
+        let content =
+            fs::read_to_string(path).map_err(|e| format!("could not read lp file: {}", e))?;
+        Self::parse_lp(&content)
+    }
+}
+
+fn parse_bound_line(line: &str, variables: &mut VariableTable) -> Result<(), String> {
+    let lower = line.to_lowercase();
+    if lower.ends_with("free") {
+        let name = line.split_whitespace().next().unwrap_or_default();
+        variables.entry(name).bounds = VarBounds::Continuous {
+            lower: f64::NEG_INFINITY,
+            upper: f64::INFINITY,
+        };
+        return Ok(());
+    }
+
+    let parts: Vec<&str> = line.split("<=").map(|s| s.trim()).collect();
+    match parts.as_slice() {
+        [lower, name, upper] => {
+            let lower: f64 = lower
+                .parse()
+                .map_err(|e| format!("invalid lower bound {:?}: {}", lower, e))?;
+            let upper: f64 = upper
+                .parse()
+                .map_err(|e| format!("invalid upper bound {:?}: {}", upper, e))?;
+            variables.entry(name).bounds = VarBounds::Continuous { lower, upper };
+            Ok(())
+        }
+        [first, second] => {
+            if let Ok(lower) = first.parse::<f64>() {
+                let variable = variables.entry(second);
+                let upper = match variable.bounds {
+                    VarBounds::Continuous { upper, .. } => upper,
+                    _ => f64::INFINITY,
+                };
+                variable.bounds = VarBounds::Continuous { lower, upper };
+            } else {
+                let upper: f64 = second
+                    .parse()
+                    .map_err(|e| format!("invalid upper bound {:?}: {}", second, e))?;
+                let variable = variables.entry(first);
+                let lower = match variable.bounds {
+                    VarBounds::Continuous { lower, .. } => lower,
+                    _ => 0.0,
+                };
+                variable.bounds = VarBounds::Continuous { lower, upper };
+            }
+            Ok(())
+        }
+        _ => Err(format!("unsupported bounds line: {:?}", line)),
+    }
+}
+
+fn parse_expression(s: &str) -> Result<LpExpression, String> {
+    let normalized = s.trim().replace(" - ", " + -");
+    let mut terms = Vec::new();
+    for term in normalized.split(" + ") {
+        let term = term.trim();
+        if term.is_empty() {
+            continue;
+        }
+        let (sign, rest) = match term.strip_prefix('-') {
+            Some(rest) => (-1.0, rest),
+            None => (1.0, term),
+        };
+        let mut tokens = rest.split_whitespace();
+        let first = tokens
+            .next()
+            .ok_or_else(|| format!("empty term in expression {:?}", s))?;
+        let (coef, name) = match (first.parse::<f64>(), tokens.next()) {
+            (Ok(coef), Some(var)) => (coef, var),
+            _ => (1.0, first),
+        };
+        terms.push((name.to_string(), sign * coef));
+    }
+    Ok(terms)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_lp_format() {
+        let mut problem = Problem::new("demo", LpObjective::Maximize);
+        problem.objective = vec![("a".to_string(), 10.0), ("b".to_string(), 20.0)];
+        problem.constraints.push(LpConstraint {
+            name: "c1".to_string(),
+            expression: vec![("a".to_string(), 1.0), ("b".to_string(), 1.0)],
+            operator: Operator::LessOrEqual,
+            rhs: 10.0,
+        });
+        problem.variables.push(LpVariable {
+            name: "a".to_string(),
+            bounds: VarBounds::Continuous {
+                lower: 0.0,
+                upper: f64::INFINITY,
+            },
+        });
+        problem.variables.push(LpVariable {
+            name: "b".to_string(),
+            bounds: VarBounds::Integer { lower: 0, upper: 5 },
+        });
+
+        let parsed = Problem::parse_lp(&problem.to_lp_file_format()).unwrap();
+
+        assert_eq!(parsed.objective_direction, LpObjective::Maximize);
+        assert_eq!(parsed.objective, problem.objective);
+        assert_eq!(parsed.constraints.len(), 1);
+        assert_eq!(parsed.constraints[0].rhs, 10.0);
+        assert!(matches!(
+            parsed.variables.iter().find(|v| v.name == "b").unwrap().bounds,
+            VarBounds::Integer { lower: 0, upper: 5 }
+        ));
+    }
+
+    #[test]
+    fn parses_negative_leading_term() {
+        let expr = parse_expression("-a + 2 b - c").unwrap();
+        assert_eq!(
+            expr,
+            vec![
+                ("a".to_string(), -1.0),
+                ("b".to_string(), 2.0),
+                ("c".to_string(), -1.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn mps_columns_includes_a_variable_only_mentioned_in_bounds() {
+        let mut problem = Problem::new("demo", LpObjective::Maximize);
+        problem.objective = vec![("a".to_string(), 1.0)];
+        problem.variables.push(LpVariable {
+            name: "a".to_string(),
+            bounds: VarBounds::Continuous {
+                lower: 0.0,
+                upper: f64::INFINITY,
+            },
+        });
+        problem.variables.push(LpVariable {
+            name: "b".to_string(),
+            bounds: VarBounds::Continuous {
+                lower: 0.0,
+                upper: 5.0,
+            },
+        });
+
+        let mps = problem.to_mps_file_format();
+        let columns = mps
+            .split("COLUMNS\n")
+            .nth(1)
+            .unwrap()
+            .split("RHS\n")
+            .next()
+            .unwrap();
+        assert!(columns.contains(" b "), "got:\n{columns}");
+        assert!(mps.contains(" UP BND  b  5"));
+    }
+
+    #[test]
+    fn mps_semi_free_variable_uses_mi_bound() {
+        let mut problem = Problem::new("demo", LpObjective::Minimize);
+        problem.objective = vec![("a".to_string(), 1.0)];
+        problem.variables.push(LpVariable {
+            name: "a".to_string(),
+            bounds: VarBounds::Continuous {
+                lower: f64::NEG_INFINITY,
+                upper: 5.0,
+            },
+        });
+
+        let mps = problem.to_mps_file_format();
+        assert!(mps.contains(" MI BND  a\n"));
+        assert!(mps.contains(" UP BND  a  5"));
+        assert!(!mps.contains("-inf"));
+    }
+
+    #[test]
+    fn mps_rows_rhs_and_bounds_cover_every_constraint_and_variable_kind() {
+        let mut problem = Problem::new("demo", LpObjective::Minimize);
+        problem.objective = vec![
+            ("a".to_string(), 1.0),
+            ("b".to_string(), 1.0),
+            ("c".to_string(), 1.0),
+        ];
+        problem.constraints.push(LpConstraint {
+            name: "c1".to_string(),
+            expression: vec![("a".to_string(), 1.0), ("b".to_string(), 1.0)],
+            operator: Operator::LessOrEqual,
+            rhs: 10.0,
+        });
+        problem.constraints.push(LpConstraint {
+            name: "c2".to_string(),
+            expression: vec![("a".to_string(), 1.0), ("c".to_string(), 1.0)],
+            operator: Operator::GreaterOrEqual,
+            rhs: 1.0,
+        });
+        problem.constraints.push(LpConstraint {
+            name: "c3".to_string(),
+            expression: vec![("b".to_string(), 1.0), ("c".to_string(), 1.0)],
+            operator: Operator::Equal,
+            rhs: 3.0,
+        });
+        // `a` keeps the implicit [0, +inf) bound, so it gets no BOUNDS line.
+        problem.variables.push(LpVariable {
+            name: "a".to_string(),
+            bounds: VarBounds::Continuous {
+                lower: 0.0,
+                upper: f64::INFINITY,
+            },
+        });
+        problem.variables.push(LpVariable {
+            name: "b".to_string(),
+            bounds: VarBounds::Integer { lower: 0, upper: 5 },
+        });
+        problem.variables.push(LpVariable {
+            name: "c".to_string(),
+            bounds: VarBounds::Binary,
+        });
+
+        let mps = problem.to_mps_file_format();
+
+        let rows = mps.split("ROWS\n").nth(1).unwrap().split("COLUMNS\n").next().unwrap();
+        assert_eq!(rows, " N  obj\n L  c1\n G  c2\n E  c3\n");
+
+        let rhs = mps.split("RHS\n").nth(1).unwrap().split("BOUNDS\n").next().unwrap();
+        assert_eq!(
+            rhs,
+            "    RHS  c1  10\n    RHS  c2  1\n    RHS  c3  3\n"
+        );
+
+        let bounds = mps.split("BOUNDS\n").nth(1).unwrap().split("ENDATA\n").next().unwrap();
+        assert_eq!(
+            bounds,
+            " LI BND  b  0\n UI BND  b  5\n BV BND  c\n"
+        );
+    }
+}
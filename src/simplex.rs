@@ -0,0 +1,531 @@
+//! A pure-Rust, dependency-free simplex solver for continuous LP
+//! relaxations.
+//!
+//! Unlike the backends in [`crate::solvers`], this does not shell out to an
+//! external binary: it builds a standard-form tableau directly from an
+//! [`LpProblem`] and runs a two-phase primal simplex. Integer/binary
+//! variable kinds are ignored — this solves the LP relaxation, not the MIP.
+//! Enable the `simplex` feature to use it.
+
+use std::collections::HashMap;
+
+use crate::lp_format::{LpObjective, LpProblem, Operator, VarBounds};
+use crate::solvers::{Solution, Status};
+
+/// A solver that runs entirely in-process, without an external binary.
+pub trait InProcessSolver {
+    /// Solve `problem`'s LP relaxation and return its solution.
+    fn solve<'a, P: LpProblem<'a>>(&self, problem: &'a P) -> Result<Solution, String>;
+}
+
+/// A two-phase primal simplex solver for continuous LP relaxations.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SimplexSolver;
+
+impl SimplexSolver {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl InProcessSolver for SimplexSolver {
+    fn solve<'a, P: LpProblem<'a>>(&self, problem: &'a P) -> Result<Solution, String> {
+        Tableau::build(problem).solve()
+    }
+}
+
+const EPS: f64 = 1e-9;
+
+enum PivotOutcome {
+    Optimal,
+    Unbounded,
+}
+
+/// How a decision variable maps onto tableau columns, depending on whether
+/// it has a finite lower bound.
+#[derive(Debug, Clone, Copy)]
+enum VarColumn {
+    /// A variable with a finite lower bound `L`: represented by one column
+    /// holding `x - L`, which is non-negative by construction.
+    Shifted(usize),
+    /// A variable with no finite lower bound (`free`): represented as the
+    /// difference of two non-negative columns, `x = plus - minus`.
+    Split { plus: usize, minus: usize },
+}
+
+/// One normalized row of the tableau before slack/artificial columns are
+/// attached: a linear combination of decision columns, a relational
+/// operator and an RHS.
+struct BoundRow {
+    terms: Vec<(usize, f64)>,
+    operator: Operator,
+    rhs: f64,
+}
+
+/// The standard-form tableau for a two-phase simplex run: one row per
+/// constraint, one column per decision/slack/surplus/artificial variable,
+/// plus a trailing RHS column.
+struct Tableau {
+    rows: Vec<Vec<f64>>,
+    basis: Vec<usize>,
+    var_names: Vec<String>,
+    columns: Vec<VarColumn>,
+    lower_bounds: Vec<f64>,
+    n_artificial: usize,
+    artificial_start: usize,
+    objective: Vec<f64>,
+    maximize: bool,
+    /// `sum(coef * lower_bound)` over every shifted variable in the
+    /// objective, added back once the shifted problem has been solved.
+    objective_constant: f64,
+}
+
+impl Tableau {
+    fn build<'a, P: LpProblem<'a>>(problem: &'a P) -> Tableau {
+        let variables: Vec<_> = problem.variables().collect();
+        let var_names: Vec<String> = variables.iter().map(|v| v.name.clone()).collect();
+        let index_of: HashMap<&str, usize> = var_names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.as_str(), i))
+            .collect();
+
+        // Assign tableau columns per variable: a bounded-below variable gets
+        // one column for `x - lower`; a variable with no finite lower bound
+        // (free, or semi-free with only an upper bound) is split into the
+        // difference of two non-negative columns.
+        let mut bounds = Vec::with_capacity(variables.len());
+        let mut columns = Vec::with_capacity(variables.len());
+        let mut lower_bounds = Vec::with_capacity(variables.len());
+        let mut next_col = 0usize;
+        for variable in &variables {
+            let (lower, upper) = match variable.bounds {
+                VarBounds::Continuous { lower, upper } => (lower, upper),
+                VarBounds::Integer { lower, upper } => (lower as f64, upper as f64),
+                VarBounds::Binary => (0.0, 1.0),
+            };
+            bounds.push((lower, upper));
+            lower_bounds.push(if lower.is_finite() { lower } else { 0.0 });
+            if lower.is_finite() {
+                columns.push(VarColumn::Shifted(next_col));
+                next_col += 1;
+            } else {
+                columns.push(VarColumn::Split {
+                    plus: next_col,
+                    minus: next_col + 1,
+                });
+                next_col += 2;
+            }
+        }
+        let n_var_cols = next_col;
+
+        // Every row, normalized to decision-column terms: the problem's own
+        // constraints, plus one `<=` row per finite upper bound (a finite
+        // lower bound is instead folded into the column's shift).
+        let mut bound_rows: Vec<BoundRow> = problem
+            .constraints()
+            .map(|c| {
+                let mut terms = Vec::with_capacity(c.expression.len());
+                let mut rhs = c.rhs;
+                for (name, coef) in &c.expression {
+                    let Some(&pos) = index_of.get(name.as_str()) else {
+                        continue;
+                    };
+                    match columns[pos] {
+                        VarColumn::Shifted(col) => {
+                            terms.push((col, *coef));
+                            rhs -= coef * lower_bounds[pos];
+                        }
+                        VarColumn::Split { plus, minus } => {
+                            terms.push((plus, *coef));
+                            terms.push((minus, -*coef));
+                        }
+                    }
+                }
+                BoundRow {
+                    terms,
+                    operator: c.operator,
+                    rhs,
+                }
+            })
+            .collect();
+
+        for (pos, &(lower, upper)) in bounds.iter().enumerate() {
+            if !upper.is_finite() {
+                continue;
+            }
+            let (terms, rhs) = match columns[pos] {
+                VarColumn::Shifted(col) => (vec![(col, 1.0)], upper - lower),
+                VarColumn::Split { plus, minus } => (vec![(plus, 1.0), (minus, -1.0)], upper),
+            };
+            bound_rows.push(BoundRow {
+                terms,
+                operator: Operator::LessOrEqual,
+                rhs,
+            });
+        }
+
+        // Normalize every row to a non-negative RHS, flipping its operator
+        // if that requires negating the row.
+        let kinds: Vec<Operator> = bound_rows
+            .iter()
+            .map(|r| match (r.operator, r.rhs < 0.0) {
+                (op, false) => op,
+                (Operator::LessOrEqual, true) => Operator::GreaterOrEqual,
+                (Operator::GreaterOrEqual, true) => Operator::LessOrEqual,
+                (Operator::Equal, true) => Operator::Equal,
+            })
+            .collect();
+
+        let n_slack = kinds
+            .iter()
+            .filter(|k| matches!(k, Operator::LessOrEqual | Operator::GreaterOrEqual))
+            .count();
+        let n_artificial = kinds
+            .iter()
+            .filter(|k| matches!(k, Operator::GreaterOrEqual | Operator::Equal))
+            .count();
+
+        let slack_start = n_var_cols;
+        let artificial_start = n_var_cols + n_slack;
+        let total_cols = artificial_start + n_artificial + 1; // + rhs
+
+        let mut rows = vec![vec![0.0; total_cols]; bound_rows.len()];
+        let mut basis = vec![0usize; bound_rows.len()];
+        let mut next_slack = slack_start;
+        let mut next_artificial = artificial_start;
+
+        for (i, r) in bound_rows.iter().enumerate() {
+            let sign = if r.rhs < 0.0 { -1.0 } else { 1.0 };
+            for (col, coef) in &r.terms {
+                rows[i][*col] += sign * coef;
+            }
+            rows[i][total_cols - 1] = sign * r.rhs;
+
+            match kinds[i] {
+                Operator::LessOrEqual => {
+                    rows[i][next_slack] = 1.0;
+                    basis[i] = next_slack;
+                    next_slack += 1;
+                }
+                Operator::GreaterOrEqual => {
+                    rows[i][next_slack] = -1.0;
+                    next_slack += 1;
+                    rows[i][next_artificial] = 1.0;
+                    basis[i] = next_artificial;
+                    next_artificial += 1;
+                }
+                Operator::Equal => {
+                    rows[i][next_artificial] = 1.0;
+                    basis[i] = next_artificial;
+                    next_artificial += 1;
+                }
+            }
+        }
+
+        let maximize = problem.objective_direction() == LpObjective::Maximize;
+        let mut objective = vec![0.0; total_cols];
+        let mut objective_constant = 0.0;
+        for (name, coef) in problem.objective() {
+            let Some(&pos) = index_of.get(name.as_str()) else {
+                continue;
+            };
+            match columns[pos] {
+                VarColumn::Shifted(col) => {
+                    objective[col] = if maximize { -coef } else { *coef };
+                    objective_constant += coef * lower_bounds[pos];
+                }
+                VarColumn::Split { plus, minus } => {
+                    objective[plus] = if maximize { -coef } else { *coef };
+                    objective[minus] = if maximize { *coef } else { -coef };
+                }
+            }
+        }
+
+        Tableau {
+            rows,
+            basis,
+            var_names,
+            columns,
+            lower_bounds,
+            n_artificial,
+            artificial_start,
+            objective,
+            maximize,
+            objective_constant,
+        }
+    }
+
+    /// The current value of tableau column `col`: its basic value if it's
+    /// in the basis, zero otherwise.
+    fn column_value(&self, col: usize) -> f64 {
+        self.basis
+            .iter()
+            .position(|&b| b == col)
+            .map(|row| self.rows[row][self.rows[row].len() - 1])
+            .unwrap_or(0.0)
+    }
+
+    fn solve(mut self) -> Result<Solution, String> {
+        if self.n_artificial > 0 {
+            let mut phase1_cost = vec![0.0; self.objective.len()];
+            for cost in phase1_cost
+                .iter_mut()
+                .skip(self.artificial_start)
+                .take(self.n_artificial)
+            {
+                *cost = 1.0;
+            }
+            let mut phase1_rc = Self::reduced_costs(&self.rows, &self.basis, &phase1_cost);
+            // A phase 1 problem (driving artificials to zero) is always
+            // bounded below by zero, so it cannot come back unbounded.
+            Self::pivot_to_optimum(&mut self.rows, &mut self.basis, &mut phase1_rc);
+
+            let phase1_value = -phase1_rc[phase1_rc.len() - 1];
+            if phase1_value.abs() > 1e-6 {
+                return Ok(Solution {
+                    status: Status::Infeasible,
+                    objective: None,
+                    results: HashMap::new(),
+                });
+            }
+
+            // Pivot out any artificial still basic (necessarily at zero) so
+            // it can't re-enter, then drop the artificial columns entirely.
+            for row in 0..self.rows.len() {
+                if self.basis[row] >= self.artificial_start {
+                    if let Some(col) = (0..self.artificial_start)
+                        .find(|&j| self.rows[row][j].abs() > EPS)
+                    {
+                        Self::do_pivot(&mut self.rows, &mut self.basis, &mut phase1_rc, row, col);
+                    }
+                }
+            }
+            for row in self.rows.iter_mut() {
+                row.drain(self.artificial_start..self.artificial_start + self.n_artificial);
+            }
+            self.objective
+                .drain(self.artificial_start..self.artificial_start + self.n_artificial);
+        }
+
+        let mut rc = Self::reduced_costs(&self.rows, &self.basis, &self.objective);
+        if let PivotOutcome::Unbounded =
+            Self::pivot_to_optimum(&mut self.rows, &mut self.basis, &mut rc)
+        {
+            return Ok(Solution {
+                status: Status::Unbounded,
+                objective: None,
+                results: HashMap::new(),
+            });
+        }
+
+        let min_value = -rc[rc.len() - 1];
+        let objective_value =
+            (if self.maximize { -min_value } else { min_value }) + self.objective_constant;
+
+        let mut results = HashMap::with_capacity(self.var_names.len());
+        for (pos, name) in self.var_names.iter().enumerate() {
+            let value = match self.columns[pos] {
+                VarColumn::Shifted(col) => self.column_value(col) + self.lower_bounds[pos],
+                VarColumn::Split { plus, minus } => {
+                    self.column_value(plus) - self.column_value(minus)
+                }
+            };
+            results.insert(name.clone(), value as f32);
+        }
+
+        Ok(Solution {
+            status: Status::Optimal,
+            objective: Some(objective_value),
+            results,
+        })
+    }
+
+    /// `reduced_costs[j] = cost[j] - sum_row(cost[basis[row]] * rows[row][j])`,
+    /// so the trailing entry is the negated value of `cost` at the current
+    /// basic feasible solution.
+    fn reduced_costs(rows: &[Vec<f64>], basis: &[usize], cost: &[f64]) -> Vec<f64> {
+        let mut rc = cost.to_vec();
+        for (row, &b) in basis.iter().enumerate() {
+            let cb = cost[b];
+            if cb == 0.0 {
+                continue;
+            }
+            for (j, value) in rc.iter_mut().enumerate() {
+                *value -= cb * rows[row][j];
+            }
+        }
+        rc
+    }
+
+    /// Bland's-rule primal simplex: pivot on the lowest-index column with a
+    /// negative reduced cost until none remain (optimal) or a column has no
+    /// positive entry to ratio-test against (unbounded).
+    fn pivot_to_optimum(rows: &mut [Vec<f64>], basis: &mut [usize], rc: &mut [f64]) -> PivotOutcome {
+        let cols = rc.len();
+        loop {
+            let entering = (0..cols - 1).find(|&j| rc[j] < -EPS);
+            let Some(col) = entering else {
+                return PivotOutcome::Optimal;
+            };
+
+            let mut leaving_row = None;
+            let mut best_ratio = f64::INFINITY;
+            for (row, r) in rows.iter().enumerate() {
+                if r[col] > EPS {
+                    let ratio = r[cols - 1] / r[col];
+                    let better = match leaving_row {
+                        None => true,
+                        Some(lr) => {
+                            ratio < best_ratio - EPS
+                                || (ratio < best_ratio + EPS && basis[lr] > basis[row])
+                        }
+                    };
+                    if better {
+                        best_ratio = ratio;
+                        leaving_row = Some(row);
+                    }
+                }
+            }
+
+            let Some(row) = leaving_row else {
+                return PivotOutcome::Unbounded;
+            };
+            Self::do_pivot(rows, basis, rc, row, col);
+        }
+    }
+
+    fn do_pivot(rows: &mut [Vec<f64>], basis: &mut [usize], rc: &mut [f64], row: usize, col: usize) {
+        let pivot = rows[row][col];
+        for v in rows[row].iter_mut() {
+            *v /= pivot;
+        }
+
+        let pivot_row = rows[row].clone();
+        for (r, other) in rows.iter_mut().enumerate() {
+            if r == row {
+                continue;
+            }
+            let factor = other[col];
+            if factor == 0.0 {
+                continue;
+            }
+            for (v, pv) in other.iter_mut().zip(pivot_row.iter()) {
+                *v -= factor * pv;
+            }
+        }
+
+        let factor = rc[col];
+        if factor != 0.0 {
+            for (v, pv) in rc.iter_mut().zip(pivot_row.iter()) {
+                *v -= factor * pv;
+            }
+        }
+
+        basis[row] = col;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::problem::Problem;
+
+    #[test]
+    fn solves_a_simple_optimal_problem() {
+        let mut problem = Problem::new("demo", LpObjective::Maximize);
+        problem.objective = vec![("a".to_string(), 1.0), ("b".to_string(), 1.0)];
+        problem.constraints.push(crate::lp_format::LpConstraint {
+            name: "c1".to_string(),
+            expression: vec![("a".to_string(), 1.0), ("b".to_string(), 1.0)],
+            operator: Operator::LessOrEqual,
+            rhs: 10.0,
+        });
+        problem.variables.push(crate::lp_format::LpVariable {
+            name: "a".to_string(),
+            bounds: VarBounds::Continuous { lower: 0.0, upper: f64::INFINITY },
+        });
+        problem.variables.push(crate::lp_format::LpVariable {
+            name: "b".to_string(),
+            bounds: VarBounds::Continuous { lower: 0.0, upper: f64::INFINITY },
+        });
+
+        let solution = SimplexSolver::new().solve(&problem).unwrap();
+        assert_eq!(solution.status, Status::Optimal);
+        assert_eq!(solution.objective, Some(10.0));
+    }
+
+    #[test]
+    fn honors_an_explicit_upper_bound() {
+        let mut problem = Problem::new("demo", LpObjective::Maximize);
+        problem.objective = vec![("a".to_string(), 1.0)];
+        problem.variables.push(crate::lp_format::LpVariable {
+            name: "a".to_string(),
+            bounds: VarBounds::Continuous { lower: 0.0, upper: 5.0 },
+        });
+
+        let solution = SimplexSolver::new().solve(&problem).unwrap();
+        assert_eq!(solution.status, Status::Optimal);
+        assert_eq!(solution.objective, Some(5.0));
+        assert_eq!(solution.results["a"], 5.0);
+    }
+
+    #[test]
+    fn a_free_variable_can_go_negative() {
+        let mut problem = Problem::new("demo", LpObjective::Minimize);
+        problem.objective = vec![("a".to_string(), 1.0)];
+        problem.constraints.push(crate::lp_format::LpConstraint {
+            name: "c1".to_string(),
+            expression: vec![("a".to_string(), 1.0)],
+            operator: Operator::GreaterOrEqual,
+            rhs: -5.0,
+        });
+        problem.variables.push(crate::lp_format::LpVariable {
+            name: "a".to_string(),
+            bounds: VarBounds::Continuous { lower: f64::NEG_INFINITY, upper: f64::INFINITY },
+        });
+
+        let solution = SimplexSolver::new().solve(&problem).unwrap();
+        assert_eq!(solution.status, Status::Optimal);
+        assert_eq!(solution.objective, Some(-5.0));
+        assert_eq!(solution.results["a"], -5.0);
+    }
+
+    #[test]
+    fn detects_infeasible_problems() {
+        let mut problem = Problem::new("demo", LpObjective::Maximize);
+        problem.objective = vec![("a".to_string(), 1.0)];
+        problem.constraints.push(crate::lp_format::LpConstraint {
+            name: "c1".to_string(),
+            expression: vec![("a".to_string(), 1.0)],
+            operator: Operator::LessOrEqual,
+            rhs: 1.0,
+        });
+        problem.constraints.push(crate::lp_format::LpConstraint {
+            name: "c2".to_string(),
+            expression: vec![("a".to_string(), 1.0)],
+            operator: Operator::GreaterOrEqual,
+            rhs: 2.0,
+        });
+        problem.variables.push(crate::lp_format::LpVariable {
+            name: "a".to_string(),
+            bounds: VarBounds::Continuous { lower: 0.0, upper: f64::INFINITY },
+        });
+
+        let solution = SimplexSolver::new().solve(&problem).unwrap();
+        assert_eq!(solution.status, Status::Infeasible);
+    }
+
+    #[test]
+    fn detects_unbounded_problems() {
+        let mut problem = Problem::new("demo", LpObjective::Maximize);
+        problem.objective = vec![("a".to_string(), 1.0)];
+        problem.variables.push(crate::lp_format::LpVariable {
+            name: "a".to_string(),
+            bounds: VarBounds::Continuous { lower: 0.0, upper: f64::INFINITY },
+        });
+
+        let solution = SimplexSolver::new().solve(&problem).unwrap();
+        assert_eq!(solution.status, Status::Unbounded);
+    }
+}
@@ -0,0 +1,123 @@
+//! The COIN-OR CBC mixed-integer solver.
+
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+use crate::lp_format::LpProblem;
+use crate::solvers::{Solution, SolverProgram, SolverWithSolutionParsing, Status};
+
+/// The COIN-OR CBC solver, invoked via its `cbc` command-line binary.
+#[derive(Debug, Clone)]
+pub struct CbcSolver {
+    command: String,
+    temp_solution_file: Option<String>,
+}
+
+impl CbcSolver {
+    pub fn new() -> Self {
+        Self {
+            command: "cbc".into(),
+            temp_solution_file: None,
+        }
+    }
+
+    /// Use a fixed path for the solution file instead of a generated temp
+    /// path.
+    pub fn with_temp_solution_file(&self, path: String) -> Self {
+        Self {
+            temp_solution_file: Some(path),
+            ..self.clone()
+        }
+    }
+}
+
+impl Default for CbcSolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SolverProgram for CbcSolver {
+    fn command_name(&self) -> &str {
+        &self.command
+    }
+
+    fn arguments(&self, lp_file: &Path, solution_file: &Path) -> Vec<OsString> {
+        vec![
+            lp_file.into(),
+            "solve".into(),
+            "solution".into(),
+            solution_file.into(),
+        ]
+    }
+
+    fn parse_stdout_status(&self, _stdout: &[u8]) -> Option<Status> {
+        None
+    }
+
+    fn solution_suffix(&self) -> Option<&str> {
+        Some(".sol")
+    }
+
+    fn solution_file_override(&self) -> Option<&str> {
+        self.temp_solution_file.as_deref()
+    }
+}
+
+impl SolverWithSolutionParsing for CbcSolver {
+    fn read_specific_solution<'a, P: LpProblem<'a>>(
+        &self,
+        f: &File,
+        problem: Option<&'a P>,
+    ) -> Result<Solution, String> {
+        let len = problem.map(|p| p.variables().size_hint().0).unwrap_or(0);
+        let mut lines = BufReader::new(f).lines();
+
+        let header = lines
+            .next()
+            .ok_or_else(|| "empty solution file".to_string())?
+            .map_err(|e| format!("could not read solution file: {}", e))?;
+
+        let status = if header.starts_with("Optimal") {
+            Status::Optimal
+        } else if header.starts_with("Unbounded") {
+            Status::Unbounded
+        } else if header.starts_with("Stopped on") {
+            // CBC hit a time limit or gap tolerance with a feasible
+            // incumbent in hand rather than proving optimality.
+            Status::SubOptimal
+        } else {
+            Status::Infeasible
+        };
+
+        let objective = header
+            .rsplit("objective value")
+            .next()
+            .filter(|_| header.contains("objective value"))
+            .and_then(|rest| rest.trim().parse().ok());
+
+        let mut results = HashMap::with_capacity(len);
+        for line in lines {
+            let line = line.map_err(|e| format!("could not read solution file: {}", e))?;
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            let (name, value) = match tokens.as_slice() {
+                [index, name, value, ..] if index.parse::<usize>().is_ok() => (*name, *value),
+                [name, value, ..] => (*name, *value),
+                _ => continue,
+            };
+            let value: f32 = value
+                .parse()
+                .map_err(|e| format!("invalid variable value for {}: {}", name, e))?;
+            results.insert(name.to_string(), value);
+        }
+
+        Ok(Solution {
+            status,
+            objective,
+            results,
+        })
+    }
+}
@@ -83,7 +83,9 @@ impl SolverProgram for Cplex {
     }
 
     fn parse_stdout_status(&self, stdout: &[u8]) -> Option<Status> {
-        if buf_contains(stdout, "No solution exists") {
+        if buf_contains(stdout, "infeasible or unbounded") {
+            Some(Status::InfeasibleOrUnbounded)
+        } else if buf_contains(stdout, "No solution exists") {
             Some(Status::Infeasible)
         } else {
             None
@@ -105,6 +107,7 @@ impl SolverWithSolutionParsing for Cplex {
         let parser = EventReader::new(f);
         let mut solution = Solution {
             status: Status::Optimal,
+            objective: None,
             results: HashMap::with_capacity(len),
         };
         for e in parser {
@@ -112,7 +115,23 @@ impl SolverWithSolutionParsing for Cplex {
                 Ok(XmlEvent::StartElement {
                     name, attributes, ..
                 }) => {
-                    if name.local_name == "variable" {
+                    if name.local_name == "header" {
+                        for attr in attributes {
+                            match attr.name.local_name.as_str() {
+                                "objectiveValue" => solution.objective = attr.value.parse().ok(),
+                                // CPLEX reports a solve that stopped on a gap
+                                // or time limit as "available" rather than
+                                // proven optimal.
+                                "solutionStatusString"
+                                    if attr.value.contains("tolerance")
+                                        || attr.value.contains("time limit") =>
+                                {
+                                    solution.status = Status::SubOptimal;
+                                }
+                                _ => {}
+                            }
+                        }
+                    } else if name.local_name == "variable" {
                         let mut name = None;
                         let mut value = None;
                         for attr in attributes {
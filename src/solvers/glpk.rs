@@ -0,0 +1,132 @@
+//! The GLPK (`glpsol`) solver.
+
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+use crate::lp_format::LpProblem;
+use crate::solvers::{Solution, SolverProgram, SolverWithSolutionParsing, Status};
+
+/// The GNU Linear Programming Kit, invoked via its `glpsol` binary.
+#[derive(Debug, Clone)]
+pub struct GlpkSolver {
+    command: String,
+    temp_solution_file: Option<String>,
+}
+
+impl GlpkSolver {
+    pub fn new() -> Self {
+        Self {
+            command: "glpsol".into(),
+            temp_solution_file: None,
+        }
+    }
+
+    /// Use a fixed path for the solution file instead of a generated temp
+    /// path.
+    pub fn with_temp_solution_file(&self, path: String) -> Self {
+        Self {
+            temp_solution_file: Some(path),
+            ..self.clone()
+        }
+    }
+}
+
+impl Default for GlpkSolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SolverProgram for GlpkSolver {
+    fn command_name(&self) -> &str {
+        &self.command
+    }
+
+    fn arguments(&self, lp_file: &Path, solution_file: &Path) -> Vec<OsString> {
+        vec![
+            "--lp".into(),
+            lp_file.into(),
+            "-o".into(),
+            solution_file.into(),
+        ]
+    }
+
+    fn parse_stdout_status(&self, _stdout: &[u8]) -> Option<Status> {
+        None
+    }
+
+    fn solution_suffix(&self) -> Option<&str> {
+        Some(".sol")
+    }
+
+    fn solution_file_override(&self) -> Option<&str> {
+        self.temp_solution_file.as_deref()
+    }
+}
+
+impl SolverWithSolutionParsing for GlpkSolver {
+    fn read_specific_solution<'a, P: LpProblem<'a>>(
+        &self,
+        f: &File,
+        problem: Option<&'a P>,
+    ) -> Result<Solution, String> {
+        let len = problem.map(|p| p.variables().size_hint().0).unwrap_or(0);
+        let mut status = Status::InfeasibleOrUnbounded;
+        let mut objective = None;
+        let mut results = HashMap::with_capacity(len);
+        let mut in_columns = false;
+
+        for line in BufReader::new(f).lines() {
+            let line = line.map_err(|e| format!("could not read solution file: {}", e))?;
+
+            if let Some(rest) = line.trim_start().strip_prefix("Status:") {
+                // GLPK reports GLP_OPT as OPTIMAL and a feasible-but-not-proven-
+                // optimal incumbent (GLP_FEAS) as FEASIBLE.
+                status = match rest.trim() {
+                    "OPTIMAL" => Status::Optimal,
+                    "FEASIBLE" => Status::SubOptimal,
+                    "UNBOUNDED" => Status::Unbounded,
+                    "INFEASIBLE" | "NOFEASIBLE" => Status::Infeasible,
+                    _ => Status::InfeasibleOrUnbounded,
+                };
+                continue;
+            }
+
+            if let Some(rest) = line.trim_start().strip_prefix("Objective:") {
+                objective = rest
+                    .split('=')
+                    .nth(1)
+                    .and_then(|rest| rest.split_whitespace().next())
+                    .and_then(|v| v.parse().ok());
+                continue;
+            }
+
+            if line.trim_start().starts_with("No.") && line.contains("Column name") {
+                in_columns = true;
+                continue;
+            }
+
+            if in_columns {
+                if line.trim().is_empty() || line.trim_start().starts_with('-') {
+                    continue;
+                }
+                let tokens: Vec<&str> = line.split_whitespace().collect();
+                // No. | Column name | St | Activity | [Lower bound] [Upper bound]
+                if let [_, name, _, activity, ..] = tokens.as_slice() {
+                    if let Ok(value) = activity.parse::<f32>() {
+                        results.insert(name.to_string(), value);
+                    }
+                }
+            }
+        }
+
+        Ok(Solution {
+            status,
+            objective,
+            results,
+        })
+    }
+}
@@ -0,0 +1,215 @@
+//! Solver backends: each wraps an external LP/MIP solver binary, writes the
+//! problem to a temp file, invokes the binary, and parses its solution file.
+
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+mod cbc;
+mod cplex;
+mod glpk;
+
+pub use cbc::CbcSolver;
+pub use cplex::Cplex;
+pub use glpk::GlpkSolver;
+
+use crate::lp_format::{InputFormat, LpFileFormat, LpProblem, MpsFileFormat};
+
+/// The outcome of a solve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// A proven-optimal solution was found.
+    Optimal,
+    /// A feasible incumbent was found, but optimality was not proven, e.g.
+    /// because the solve stopped on a MIP gap tolerance or a time limit.
+    SubOptimal,
+    /// No feasible solution exists.
+    Infeasible,
+    /// The problem is unbounded.
+    Unbounded,
+    /// The solver could not tell whether the problem is infeasible or
+    /// unbounded (common for MIP solves that never find a feasible
+    /// incumbent).
+    InfeasibleOrUnbounded,
+}
+
+/// A solve's status, objective value (when available) and variable
+/// assignments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution {
+    pub status: Status,
+    pub objective: Option<f64>,
+    pub results: HashMap<String, f32>,
+}
+
+/// A solver invoked as an external command-line program.
+pub trait SolverProgram {
+    /// The name (or path) of the binary to run.
+    fn command_name(&self) -> &str;
+
+    /// The arguments to invoke the binary with, given the generated LP file
+    /// and the path the solution should be written to.
+    fn arguments(&self, lp_file: &Path, solution_file: &Path) -> Vec<OsString>;
+
+    /// Inspect the solver's stdout for a status that doesn't require parsing
+    /// a solution file (e.g. "no solution exists").
+    fn parse_stdout_status(&self, stdout: &[u8]) -> Option<Status>;
+
+    /// The file extension the solution file should be created with, if the
+    /// solver cares about it.
+    fn solution_suffix(&self) -> Option<&str> {
+        None
+    }
+
+    /// The format the problem should be written to disk in before this
+    /// solver is invoked. Defaults to LP; a solver that only accepts MPS
+    /// (or prefers it for certain problem classes) can override this.
+    fn input_format(&self) -> InputFormat {
+        InputFormat::Lp
+    }
+
+    /// A fixed path to use for the solution file instead of a generated
+    /// temp path, if one was configured (e.g. via `with_temp_solution_file`).
+    fn solution_file_override(&self) -> Option<&str> {
+        None
+    }
+}
+
+fn temp_file_path(suffix: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("lp_solvers_{}{}", std::process::id(), suffix))
+}
+
+/// Write `problem` to a temp file in `solver`'s preferred [`InputFormat`] and
+/// return that path together with the (not yet created) solution file path.
+/// Shared by the sync and async `run` implementations.
+fn write_problem_input<'a, S, P>(solver: &S, problem: &'a P) -> Result<(PathBuf, PathBuf), String>
+where
+    S: SolverProgram + ?Sized,
+    P: LpProblem<'a> + LpFileFormat + MpsFileFormat<'a>,
+{
+    let problem_file = match solver.input_format() {
+        InputFormat::Lp => {
+            let path = temp_file_path(".lp");
+            problem
+                .write_lp(path.to_str().ok_or("invalid temp path")?)
+                .map_err(|e| format!("could not write lp file: {}", e))?;
+            path
+        }
+        InputFormat::Mps => {
+            let path = temp_file_path(".mps");
+            problem
+                .write_mps(path.to_str().ok_or("invalid temp path")?)
+                .map_err(|e| format!("could not write mps file: {}", e))?;
+            path
+        }
+    };
+
+    let solution_file = match solver.solution_file_override() {
+        Some(path) => PathBuf::from(path),
+        None => temp_file_path(solver.solution_suffix().unwrap_or(".sol")),
+    };
+    Ok((problem_file, solution_file))
+}
+
+/// A [`SolverProgram`] that, on success, produces a solution file this crate
+/// knows how to parse.
+pub trait SolverWithSolutionParsing: SolverProgram {
+    /// Parse a solution out of an already-opened solution file.
+    fn read_specific_solution<'a, P: LpProblem<'a>>(
+        &self,
+        f: &File,
+        problem: Option<&'a P>,
+    ) -> Result<Solution, String>;
+
+    /// Open `path` and parse the solution it contains.
+    fn read_solution<'a, P: LpProblem<'a>>(
+        &self,
+        path: &String,
+        problem: Option<&'a P>,
+    ) -> Result<Solution, String> {
+        let f = File::open(path).map_err(|e| format!("could not open solution file: {}", e))?;
+        self.read_specific_solution(&f, problem)
+    }
+
+    /// Write `problem` to a temp file in this solver's preferred
+    /// [`InputFormat`], run the solver binary on it, and parse the result.
+    ///
+    /// This blocks the calling thread until the solver process exits; see
+    /// [`SolverProgramAsync::run_async`] for a non-blocking equivalent.
+    fn run<'a, P>(&self, problem: &'a P) -> Result<Solution, String>
+    where
+        P: LpProblem<'a> + LpFileFormat + MpsFileFormat<'a>,
+    {
+        let (problem_file, solution_file) = write_problem_input(self, problem)?;
+
+        let output = Command::new(self.command_name())
+            .args(self.arguments(&problem_file, &solution_file))
+            .output()
+            .map_err(|e| format!("could not run {}: {}", self.command_name(), e))?;
+
+        if let Some(status) = self.parse_stdout_status(&output.stdout) {
+            return Ok(Solution {
+                status,
+                objective: None,
+                results: HashMap::new(),
+            });
+        }
+
+        self.read_solution(
+            &solution_file.to_string_lossy().into_owned(),
+            Some(problem),
+        )
+    }
+}
+
+/// A solver that supports a MIP gap tolerance.
+pub trait WithMipGap<T> {
+    fn mip_gap(&self) -> Option<f32>;
+    fn with_mip_gap(&self, mipgap: f32) -> Result<T, String>;
+}
+
+/// An async counterpart to [`SolverWithSolutionParsing::run`], for long
+/// solves that shouldn't stall the calling task: the solver binary is
+/// spawned and awaited through `tokio::process::Command` rather than
+/// `std::process::Command`, so the executor stays free to run other work
+/// while it's in flight. Enable the `async` feature to use it.
+#[cfg(feature = "async")]
+pub trait SolverProgramAsync: SolverWithSolutionParsing + Sync {
+    /// Write `problem` to a temp file, run the solver binary on it without
+    /// blocking the calling task, and parse the result.
+    fn run_async<'a, P>(
+        &self,
+        problem: &'a P,
+    ) -> impl std::future::Future<Output = Result<Solution, String>> + Send
+    where
+        P: LpProblem<'a> + LpFileFormat + MpsFileFormat<'a> + Sync,
+    {
+        async move {
+            let (problem_file, solution_file) = write_problem_input(self, problem)?;
+
+            let output = tokio::process::Command::new(self.command_name())
+                .args(self.arguments(&problem_file, &solution_file))
+                .output()
+                .await
+                .map_err(|e| format!("could not run {}: {}", self.command_name(), e))?;
+
+            if let Some(status) = self.parse_stdout_status(&output.stdout) {
+                return Ok(Solution {
+                    status,
+                    objective: None,
+                    results: HashMap::new(),
+                });
+            }
+
+            self.read_solution(
+                &solution_file.to_string_lossy().into_owned(),
+                Some(problem),
+            )
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: SolverWithSolutionParsing + Sync> SolverProgramAsync for T {}
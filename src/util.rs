@@ -0,0 +1,28 @@
+//! Small helpers shared across solver backends.
+
+/// Returns true if `needle` occurs anywhere in `haystack`.
+pub fn buf_contains(haystack: &[u8], needle: &str) -> bool {
+    let needle = needle.as_bytes();
+    if needle.is_empty() {
+        return true;
+    }
+    haystack.windows(needle.len()).any(|window| window == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::buf_contains;
+
+    #[test]
+    fn finds_substring() {
+        assert!(buf_contains(
+            b"No solution exists for this model",
+            "No solution exists"
+        ));
+    }
+
+    #[test]
+    fn missing_substring() {
+        assert!(!buf_contains(b"optimal solution found", "No solution exists"));
+    }
+}
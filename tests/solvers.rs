@@ -3,7 +3,9 @@ extern crate lp_solvers;
 use std::fs;
 
 use lp_solvers::problem::Problem;
-use lp_solvers::solvers::{CbcSolver, GlpkSolver, Solution, SolverWithSolutionParsing, Status};
+use lp_solvers::solvers::{
+    CbcSolver, Cplex, GlpkSolver, Solution, SolverWithSolutionParsing, Status,
+};
 
 #[test]
 fn cbc_optimal() {
@@ -12,6 +14,7 @@ fn cbc_optimal() {
     let Solution {
         status,
         results: mut variables,
+        ..
     } = solver
         .read_solution::<Problem>(&"cbc_optimal.sol".to_string(), None)
         .unwrap();
@@ -62,6 +65,26 @@ fn cbc_infeasible_alternative_format() {
     assert_eq!(variables.remove("b"), Some(0f32));
 }
 
+#[test]
+fn cbc_suboptimal() {
+    let _ = fs::copy(
+        "tests/solution_files/cbc_suboptimal.sol",
+        "cbc_suboptimal.sol",
+    );
+    let solver = CbcSolver::new().with_temp_solution_file("cbc_suboptimal.sol".to_string());
+    let Solution {
+        status,
+        objective,
+        results: mut variables,
+    } = solver
+        .read_solution::<Problem>(&"cbc_suboptimal.sol".to_string(), None)
+        .unwrap();
+    assert_eq!(status, Status::SubOptimal);
+    assert_eq!(objective, Some(42.0));
+    assert_eq!(variables.remove("a"), Some(2f32));
+    assert_eq!(variables.remove("b"), Some(1f32));
+}
+
 #[test]
 fn cbc_unbounded() {
     let _ = fs::copy(
@@ -118,6 +141,109 @@ fn glpk_unbounded() {
     assert_eq!(status, Status::Unbounded);
 }
 
+#[test]
+fn glpk_suboptimal() {
+    let _ = fs::copy(
+        "tests/solution_files/glpk_suboptimal.sol",
+        "glpk_suboptimal.sol",
+    );
+    let solver = GlpkSolver::new().with_temp_solution_file("glpk_suboptimal.sol".to_string());
+    let Solution {
+        status,
+        objective,
+        results: mut variables,
+    } = solver
+        .read_solution::<Problem>(&"glpk_suboptimal.sol".to_string(), None)
+        .unwrap();
+    assert_eq!(status, Status::SubOptimal);
+    assert_eq!(objective, Some(42.0));
+    assert_eq!(variables.remove("a"), Some(2f32));
+    assert_eq!(variables.remove("b"), Some(1f32));
+}
+
+#[test]
+fn glpk_infeasible_or_unbounded() {
+    let _ = fs::copy(
+        "tests/solution_files/glpk_infeasible_or_unbounded.sol",
+        "glpk_infeasible_or_unbounded.sol",
+    );
+    let solver = GlpkSolver::new()
+        .with_temp_solution_file("glpk_infeasible_or_unbounded.sol".to_string());
+    let Solution { status, .. } = solver
+        .read_solution::<Problem>(&"glpk_infeasible_or_unbounded.sol".to_string(), None)
+        .unwrap();
+    assert_eq!(status, Status::InfeasibleOrUnbounded);
+}
+
+#[test]
+fn cplex_suboptimal() {
+    let _ = fs::copy(
+        "tests/solution_files/cplex_suboptimal.sol",
+        "cplex_suboptimal.sol",
+    );
+    let solver = Cplex::default();
+    let Solution {
+        status,
+        objective,
+        results: mut variables,
+    } = solver
+        .read_solution::<Problem>(&"cplex_suboptimal.sol".to_string(), None)
+        .unwrap();
+    assert_eq!(status, Status::SubOptimal);
+    assert_eq!(objective, Some(42.0));
+    assert_eq!(variables.remove("a"), Some(2f32));
+    assert_eq!(variables.remove("b"), Some(1f32));
+}
+
+#[cfg(feature = "async")]
+mod run_async {
+    use std::ffi::OsString;
+    use std::fs::File;
+    use std::path::Path;
+
+    use lp_solvers::lp_format::{LpObjective, LpProblem};
+    use lp_solvers::problem::Problem;
+    use lp_solvers::solvers::{Solution, SolverProgram, SolverProgramAsync, SolverWithSolutionParsing, Status};
+
+    /// A solver that never actually needs a solution file: it reports its
+    /// status straight from stdout, the same shortcut CPLEX uses for
+    /// "infeasible or unbounded". Running the harmless `true` binary lets
+    /// this exercise [`SolverProgramAsync::run_async`] without depending on
+    /// CBC/GLPK/CPLEX being installed.
+    struct AlwaysOptimal;
+
+    impl SolverProgram for AlwaysOptimal {
+        fn command_name(&self) -> &str {
+            "true"
+        }
+
+        fn arguments(&self, _lp_file: &Path, _solution_file: &Path) -> Vec<OsString> {
+            Vec::new()
+        }
+
+        fn parse_stdout_status(&self, _stdout: &[u8]) -> Option<Status> {
+            Some(Status::Optimal)
+        }
+    }
+
+    impl SolverWithSolutionParsing for AlwaysOptimal {
+        fn read_specific_solution<'a, P: LpProblem<'a>>(
+            &self,
+            _f: &File,
+            _problem: Option<&'a P>,
+        ) -> Result<Solution, String> {
+            unreachable!("parse_stdout_status should short-circuit before a solution file is read")
+        }
+    }
+
+    #[tokio::test]
+    async fn does_not_block_on_the_child_process() {
+        let problem = Problem::new("demo", LpObjective::Minimize);
+        let solution = AlwaysOptimal.run_async(&problem).await.unwrap();
+        assert_eq!(solution.status, Status::Optimal);
+    }
+}
+
 #[test]
 fn glpk_empty_col_bounds() {
     let _ = fs::copy(